@@ -2,55 +2,86 @@
 
 open System
 open Commands.DispatchResult
+open Commands.Flags
 open Commands.Executor.Build
+open Commands.Executor.Check
+open Commands.Executor.Inspect
 open Commands.Executor.Lexer
 open Commands.Executor.Help
 open Commands.Executor.Parser
 open Commands.Executor.Run
 open Commands.Executor.NewProject
 
+// Diagnostic code for failures reported by the command line itself (bad arguments, missing
+// project files) when they are emitted as JSON.
+let CLI_FAILURE_CODE = "D001"
+
+let configureErrorFormat (args: string[]) : DispatchResult option =
+    match getFlagValue (args, args[0], "--error-format") with
+    | None
+    | Some "human" -> None
+    | Some "json" ->
+        FrontEnd.Error.Diagnostics.Format <- FrontEnd.Error.DiagnosticFormat.Json
+        None
+    | Some other -> Some(Failure $"--error-format expects 'human' or 'json', got '{other}'")
+
 let dispatch (args: string[]) =
     if args.Length = 0 then
         Failure "Usage: cloth <command>"
 
     else
-        match args[0] with
-        | "?"
-        | "help" -> runHelp ()
-
-        | "build" ->
-            if args.Length < 2 then
-                Failure "Expected project directory. Example: cloth build ./my-project"
-            else
-                runBuild (args[1], args)
-
-        | "run" ->
-            if args.Length < 2 then
-                Failure "Expected project directory. Example: cloth run ./my-project"
-            else
-                runRun (args[1], args)
-
-        | "test" -> Success "Test Called"
-
-        | "lexer" ->
-            if args.Length < 2 then
-                Failure "Expected file path. Example: cloth lexer ./src/Main.co"
-            else
-                runLexer (args[1], args)
-
-        | "parser" ->
-            if args.Length < 2 then
-                Failure "Expected file path. Example: cloth parser ./src/Main.co"
-            else
-                runParser (args[1], args)
-
-        | "new" ->
-            if args.Length < 2 then
-                Failure "Expected project directory. Example: cloth new ./my-project"
-            else
-                runNewProject (args[1], args)
-
-        | unknown -> Failure $"Unknown command: {unknown}"
+        match configureErrorFormat args with
+        | Some failure -> failure
+        | None ->
+            match args[0] with
+            | "?"
+            | "help" -> runHelp ()
+
+            | "build" ->
+                if args.Length < 2 then
+                    Failure "Expected project directory. Example: cloth build ./my-project"
+                else
+                    runBuild (args[1], args)
+
+            | "check" ->
+                if args.Length < 2 then
+                    Failure "Expected project directory. Example: cloth check ./my-project"
+                else
+                    runCheck (args[1], args)
+
+            | "inspect" ->
+                if args.Length < 2 then
+                    Failure "Expected project directory. Example: cloth inspect ./my-project"
+                else
+                    runInspect (args[1], args)
+
+            | "run" ->
+                if args.Length < 2 then
+                    Failure "Expected project directory. Example: cloth run ./my-project"
+                else
+                    runRun (args[1], args)
+
+            | "test" -> Success "Test Called"
+
+            | "lexer" ->
+                if args.Length < 2 then
+                    Failure "Expected file path. Example: cloth lexer ./src/Main.co"
+                else
+                    runLexer (args[1], args)
+
+            | "parser" ->
+                if args.Length < 2 then
+                    Failure "Expected file path. Example: cloth parser ./src/Main.co"
+                else
+                    runParser (args[1], args)
+
+            | "new" ->
+                if args.Length < 2 then
+                    Failure "Expected project directory. Example: cloth new ./my-project"
+                else
+                    runNewProject (args[1], args)
+
+            | unknown -> Failure $"Unknown command: {unknown}"
 
 [<EntryPoint>]
 let main (args: string[]) =
@@ -62,5 +93,11 @@ let main (args: string[]) =
         0
 
     | Failure error ->
-        eprintfn $"Error {error}"
+        if FrontEnd.Error.Diagnostics.Format = FrontEnd.Error.DiagnosticFormat.Json then
+            FrontEnd.Error.Diagnostics.WriteJson(true, CLI_FAILURE_CODE, "command failed", error)
+        else
+            eprintfn $"Error {error}"
+
         1
+
+    | ExitCode code -> code
@@ -3,3 +3,5 @@
 type DispatchResult =
     | Success of string
     | Failure of string
+    // Exit status of a program started by `run`, handed back unchanged as cloth's own.
+    | ExitCode of int
@@ -0,0 +1,15 @@
+module Commands.Executor.Check
+
+open System
+open Commands.DispatchResult
+
+let runCheck (path: string, _args: string[]) =
+    let tomlPath = IO.Path.Combine(path, "build.toml")
+
+    if not (IO.File.Exists(tomlPath)) then
+        Failure $"build.toml not found in '{path}'"
+    else
+        let compiler = Compiler.Compiler(path)
+        compiler.Check()
+
+        Success "Check completed."
@@ -18,8 +18,10 @@ let runHelp () : DispatchResult =
     eprintfn "  version                         Show compiler version"
     eprintfn "  lexer <flags> <file>            Run lexer on a source file"
     eprintfn "  parse <flags> <file>            Parse source and report syntax errors"
-    eprintfn "  check <flags> <file>            Run semantic/type checks"
-    eprintfn "  run <flags> <build_file>        Compile and execute"
+    eprintfn "  check <flags> <build_file>      Run semantic/type checks without building"
+    eprintfn "  inspect <flags> <build_file>    Print types, members and signatures (--json for tooling)"
+    eprintfn "  run <flags> <build_file> [-- <args>]"
+    eprintfn "                                  Compile and execute, passing <args> to the program"
     eprintfn "  build <flags> <build_file>      Compile to output artifact"
     eprintfn "  doc <flags> <build_file>        Generate documentation"
     eprintfn ""
@@ -32,6 +34,8 @@ let runHelp () : DispatchResult =
     eprintfn "  -Werror                         Treat warnings as errors"
     eprintfn "  -I <dir>                        Add import/include directory"
     eprintfn "  -color <mode>                   Diagnostic color: always|auto|never"
+    eprintfn "  --error-format <human|json>     Diagnostic output format (json: one object per line)"
+    eprintfn "  --watch                         Re-run whenever a source file changes (run)"
     eprintfn ""
 
     eprintfn "Debug:"
@@ -45,6 +49,7 @@ let runHelp () : DispatchResult =
     eprintfn "  cloth main.co"
     eprintfn "  cloth lexer main.co"
     eprintfn "  cloth parse src/main.co"
+    eprintfn "  cloth run ./my-project -- arg1 arg2"
     eprintfn "  cloth build \"C:\\path\\to\\build.toml\" -o out.exe"
     eprintfn "  cloth doc \"C:\\path\\to\\build.toml\""
     eprintfn ""
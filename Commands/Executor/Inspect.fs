@@ -0,0 +1,22 @@
+module Commands.Executor.Inspect
+
+open System
+open Commands.DispatchResult
+open Commands.Flags
+
+let runInspect (path: string, args: string[]) =
+    let tomlPath = IO.Path.Combine(path, "build.toml")
+
+    if not (IO.File.Exists(tomlPath)) then
+        Failure $"build.toml not found in '{path}'"
+    else
+        let compiler = Compiler.Compiler(path)
+        let cirModule = compiler.Lower()
+        let relevantFlags = getRelevantFlags (args, "inspect")
+
+        if relevantFlags |> Array.contains "--json" then
+            printfn $"{Compiler.CIR.CirPrinter.PrintOutlineJson(cirModule)}"
+        else
+            printfn $"{Compiler.CIR.CirPrinter.PrintOutline(cirModule)}"
+
+        Success ""
@@ -2,51 +2,232 @@ module Commands.Executor.Run
 
 open System
 open System.Diagnostics
+open System.Runtime.InteropServices
 open Commands.Cleanup
 open Commands.DispatchResult
+open Commands.Flags
 open Compiler.Configs
 
 let clean (dir: string) = cleanup (dir, CLEANUP_EXTENSIONS)
 
-let runRun (path: string, _args: string[]) =
-    let tomlPath = IO.Path.Combine(path, "build.toml")
+let private exePathOf (buildDir: string, config: ClothConfig) =
+    let exeName =
+        if OperatingSystem.IsWindows() then
+            config.Project.Name + ".exe"
+        else
+            config.Project.Name
 
-    if not (IO.File.Exists(tomlPath)) then
-        Failure $"build.toml not found in '{path}'"
-    else
+    IO.Path.Combine(buildDir, exeName)
+
+let private notRunnable (config: ClothConfig) =
+    Failure $"cannot run a project with output='{ClothConfig.OutputTypeToString config.Build.OutputType}' (only 'executable' is runnable)"
+
+let private programStartInfo (exePath: string, buildDir: string, args: string[]) =
+    let psi = ProcessStartInfo()
+    psi.FileName <- exePath
+    psi.WorkingDirectory <- buildDir
+    psi.UseShellExecute <- false
+    psi.CreateNoWindow <- false
+
+    for arg in getProgramArgs args do
+        psi.ArgumentList.Add(arg)
+
+    psi
+
+// Stops cloth for SIGINT/SIGTERM without leaving build intermediates behind. Before the program
+// starts, a signal lands in the middle of compilation, which cannot be unwound, so cloth cleans
+// up and exits on the spot. Once the program runs, Ctrl+C already reaches it through the
+// foreground process group and cloth only has to outlive it; SIGTERM is aimed at cloth alone,
+// so the program is killed explicitly.
+let private registerSignals (buildDir: string, running: Process option ref, signalExit: int option ref) =
+    let handle (code: int) (ctx: PosixSignalContext) =
+        ctx.Cancel <- true
+        signalExit.Value <- Some code
+
+        match running.Value with
+        | Some proc ->
+            if ctx.Signal = PosixSignal.SIGTERM then
+                proc.Kill(true)
+        | None ->
+            if IO.Directory.Exists(buildDir) then
+                clean buildDir
+
+            exit code
+
+    [ PosixSignalRegistration.Create(PosixSignal.SIGINT, Action<PosixSignalContext>(handle 130))
+      PosixSignalRegistration.Create(PosixSignal.SIGTERM, Action<PosixSignalContext>(handle 143)) ]
+
+// Compiles and executes the project once.
+let private runOnce (path: string, tomlPath: string, args: string[]) : DispatchResult =
+    let buildDir = IO.Path.Combine(path, "build")
+    let running = ref None
+    let signalExit = ref None
+    let registrations = registerSignals (buildDir, running, signalExit)
+
+    try
         let compiler = Compiler.Compiler(path)
         compiler.Compile() |> ignore
 
         let config = ConfigReader.Read(tomlPath)
 
         if config.Build.OutputType <> OutputType.Executable then
-            Failure $"cannot run a project with output='{ClothConfig.OutputTypeToString config.Build.OutputType}' (only 'executable' is runnable)"
+            notRunnable config, false
         else
-            let buildDir = IO.Path.Combine(path, "build")
-
-            let exeName =
-                if OperatingSystem.IsWindows() then
-                    config.Project.Name + ".exe"
-                else
-                    config.Project.Name
-
-            let exePath = IO.Path.Combine(buildDir, exeName)
+            let exePath = exePathOf (buildDir, config)
 
             if not (IO.File.Exists(exePath)) then
                 clean buildDir
                 Failure $"expected binary '{exePath}' was not produced by build"
             else
-                let psi = ProcessStartInfo()
-                psi.FileName <- exePath
-                psi.WorkingDirectory <- buildDir
-                psi.UseShellExecute <- false
-                psi.CreateNoWindow <- false
-
-                use proc = Process.Start(psi)
+                use proc = Process.Start(programStartInfo (exePath, buildDir, args))
+                running.Value <- Some proc
                 proc.WaitForExit()
                 clean buildDir
 
-                if proc.ExitCode = 0 then
-                    Success ""
+                match signalExit.Value with
+                | Some code -> ExitCode code
+                | None -> ExitCode proc.ExitCode
+    finally
+        for registration in registrations do
+            registration.Dispose()
+
+// The command line that started this cloth, so watch mode can run `cloth build` as a child.
+// Under `dotnet Cloth.dll` the host is dotnet itself and needs the assembly path first.
+let private selfCommand () : string * string list =
+    let host = Environment.ProcessPath
+
+    if IO.Path.GetFileNameWithoutExtension(host) = "dotnet" then
+        host, [ Reflection.Assembly.GetEntryAssembly().Location ]
+    else
+        host, []
+
+let private killQuietly (proc: Process) =
+    try
+        proc.Kill(true)
+    with :? InvalidOperationException ->
+        ()
+
+// Rebuilds and restarts the program whenever a source file changes, including while it is still
+// running. Each build runs as a child `cloth build`, so its diagnostics (which exit the process)
+// end only that build and the session keeps watching.
+let private runWatch (path: string, tomlPath: string, args: string[]) : DispatchResult =
+    let config = ConfigReader.Read(tomlPath)
+    let sourceRoot = IO.Path.Combine(path, config.Build.Source)
+
+    if config.Build.OutputType <> OutputType.Executable then
+        notRunnable config
+    elif not (IO.Directory.Exists(sourceRoot)) then
+        Failure $"source directory '{sourceRoot}' does not exist"
+    else
+        let buildDir = IO.Path.Combine(path, "build")
+        let exePath = exePathOf (buildDir, config)
+        let current: Process option ref = ref None
+        let signalExit = ref None
+        use changed = new Threading.ManualResetEventSlim(false)
+
+        let onChange () =
+            changed.Set()
+
+            match current.Value with
+            | Some proc -> killQuietly proc
+            | None -> ()
+
+        use watcher = new IO.FileSystemWatcher(sourceRoot, "*.co")
+        watcher.IncludeSubdirectories <- true
+        watcher.NotifyFilter <- IO.NotifyFilters.FileName ||| IO.NotifyFilters.LastWrite
+        watcher.Changed.Add(fun _ -> onChange ())
+        watcher.Created.Add(fun _ -> onChange ())
+        watcher.Deleted.Add(fun _ -> onChange ())
+        watcher.Renamed.Add(fun _ -> onChange ())
+        watcher.EnableRaisingEvents <- true
+
+        // Ctrl+C already reaches the running child through the process group; SIGTERM is aimed
+        // at cloth alone, so the child is killed explicitly. Either signal ends the session.
+        let handle (code: int) (ctx: PosixSignalContext) =
+            ctx.Cancel <- true
+            signalExit.Value <- Some code
+
+            match current.Value with
+            | Some proc when ctx.Signal = PosixSignal.SIGTERM -> killQuietly proc
+            | _ -> ()
+
+            changed.Set()
+
+        use _sigint = PosixSignalRegistration.Create(PosixSignal.SIGINT, Action<PosixSignalContext>(handle 130))
+        use _sigterm = PosixSignalRegistration.Create(PosixSignal.SIGTERM, Action<PosixSignalContext>(handle 143))
+
+        let buildStartInfo () =
+            let host, prefix = selfCommand ()
+            let psi = ProcessStartInfo(host)
+            psi.UseShellExecute <- false
+
+            for arg in prefix @ [ "build"; path ] do
+                psi.ArgumentList.Add(arg)
+
+            match getFlagValue (args, "run", "--error-format") with
+            | Some format ->
+                psi.ArgumentList.Add("--error-format")
+                psi.ArgumentList.Add(format)
+            | None -> ()
+
+            psi
+
+        let runChild (psi: ProcessStartInfo) =
+            use proc = Process.Start(psi)
+            current.Value <- Some proc
+            proc.WaitForExit()
+            current.Value <- None
+            proc.ExitCode
+
+        // Editors usually save in several steps; let them settle before rebuilding.
+        let settle () =
+            Threading.Thread.Sleep(100)
+            changed.Reset()
+
+        let waitForChange (message: string) =
+            printfn $"{message}"
+            changed.Wait()
+            settle ()
+
+        // A signal also sets `changed`, so every wake-up checks for one before going round again.
+        let rec loop () =
+            let buildCode = runChild (buildStartInfo ())
+
+            if signalExit.Value.IsNone then
+                if changed.IsSet then
+                    settle ()
+                    rerun "--- change detected, rebuilding ---"
+                elif buildCode <> 0 then
+                    waitForChange $"--- build failed; watching '{sourceRoot}' for changes ---"
+                    rerun "--- change detected, rebuilding ---"
                 else
-                    Failure $"program exited with code {proc.ExitCode}"
+                    let code = runChild (programStartInfo (exePath, buildDir, args))
+
+                    if changed.IsSet then
+                        settle ()
+                        rerun "--- change detected, restarting ---"
+                    else
+                        waitForChange $"--- exited with code {code}; watching '{sourceRoot}' for changes ---"
+                        rerun "--- change detected, re-running ---"
+
+        and rerun (message: string) =
+            if signalExit.Value.IsNone then
+                printfn $"{message}"
+                loop ()
+
+        loop ()
+
+        if IO.Directory.Exists(buildDir) then
+            clean buildDir
+
+        ExitCode(defaultArg signalExit.Value 0)
+
+let runRun (path: string, args: string[]) =
+    let tomlPath = IO.Path.Combine(path, "build.toml")
+
+    if not (IO.File.Exists(tomlPath)) then
+        Failure $"build.toml not found in '{path}'"
+    elif getRelevantFlags (args, "run") |> Array.contains "--watch" then
+        runWatch (path, tomlPath, args)
+    else
+        runOnce (path, tomlPath, args)
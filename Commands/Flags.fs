@@ -2,7 +2,10 @@
 
 let FLAG_DELIMITER = "--"
 
-let MAIN_COMMANDS = set [ "lexer"; "run"; "build"; "parse" ]
+// A bare "--" ends the compiler's own arguments; everything after it belongs to the program.
+let ARGS_SEPARATOR = "--"
+
+let MAIN_COMMANDS = set [ "lexer"; "run"; "build"; "check"; "inspect"; "parse" ]
 
 let getRelevantFlags (args: string[], command: string) : string[] =
     let commandIndex = args |> Array.tryFindIndex (fun arg -> arg = command)
@@ -12,5 +15,29 @@ let getRelevantFlags (args: string[], command: string) : string[] =
     | Some index ->
         args
         |> Array.skip (index + 1)
-        |> Array.takeWhile (fun arg -> not (MAIN_COMMANDS.Contains arg))
+        |> Array.takeWhile (fun arg -> not (MAIN_COMMANDS.Contains arg) && arg <> ARGS_SEPARATOR)
         |> Array.filter (fun arg -> arg.StartsWith(FLAG_DELIMITER))
+
+// Value of a `--flag value` pair given to `command`. An empty string means the flag was the last
+// argument with no value after it.
+let getFlagValue (args: string[], command: string, flag: string) : string option =
+    match args |> Array.tryFindIndex (fun arg -> arg = command) with
+    | None -> None
+    | Some index ->
+        let scoped =
+            args
+            |> Array.skip (index + 1)
+            |> Array.takeWhile (fun arg -> arg <> ARGS_SEPARATOR)
+
+        match scoped |> Array.tryFindIndex (fun arg -> arg = flag) with
+        | None -> None
+        | Some i when i + 1 < scoped.Length -> Some scoped[i + 1]
+        | Some _ -> Some ""
+
+// Only looks past the command and its project path, so a path spelled "--" is not a separator.
+let getProgramArgs (args: string[]) : string[] =
+    let afterPath = args |> Array.skip (min 2 args.Length)
+
+    match afterPath |> Array.tryFindIndex (fun arg -> arg = ARGS_SEPARATOR) with
+    | None -> [||]
+    | Some index -> afterPath |> Array.skip (index + 1)
@@ -0,0 +1,54 @@
+// Copyright (c) 2026.The Cloth contributors.
+// 
+// BuildError.cs is part of the Cloth Compiler.
+// 
+// Use, modification, and distribution of this file are governed by the
+// license terms provided with the Cloth Compiler source distribution.
+
+using FrontEnd.Error;
+
+namespace Compiler;
+
+// Failures of the build driver itself (project layout, dependency resolution, external tools),
+// as opposed to diagnostics about the source being compiled.
+public class BuildError : Exception {
+	private readonly string _code;
+	private readonly string _label;
+	private readonly bool _willExit;
+	private readonly string? _message;
+
+	private BuildError(string code, string label, bool willExit, string? message = null) : base(message ?? label) {
+		_code = code;
+		_label = label;
+		_willExit = willExit;
+		_message = message;
+	}
+
+	public static readonly BuildError BuildFileNotFound = new("B001", "build.toml not found", true);
+
+	public static readonly BuildError SourceRootNotFound = new("B002", "source directory does not exist", true);
+
+	public static readonly BuildError StdlibNotFound = new("B003", "cannot locate the Cloth standard library", true);
+
+	public static readonly BuildError DependencyLibMissing = new("B004", "dependency build did not produce a .lib in cache", true);
+
+	public static readonly BuildError ToolFailed = new("B005", "external tool failed", true);
+
+	public BuildError WithMessage(string message) => new(_code, _label, _willExit, message);
+
+	public BuildError Render() {
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(_willExit, _code, _label, _message);
+		}
+		else {
+			var type = _willExit ? "Error" : "Warning";
+			Console.Error.WriteLine($"{type}[{_code}]: {_label}");
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
+		}
+
+		if (_willExit)
+			Environment.Exit(1);
+		return this;
+	}
+}
@@ -5,6 +5,8 @@
 // Use, modification, and distribution of this file are governed by the
 // license terms provided with the Cloth Compiler source distribution.
 
+using FrontEnd.Error;
+
 namespace Compiler.CIR;
 
 public class CirError : Exception {
@@ -36,12 +38,18 @@ public class CirError : Exception {
 	public CirError WithFile(string file) => new(_code, _label, _willExit, _message, file);
 
 	public CirError Render() {
-		var type = _willExit ? "Error" : "Warning";
-		Console.Error.WriteLine($"{type}[{_code}]: {_label}");
-		if (_file != null)
-			Console.Error.WriteLine($"  --> {_file}");
-		if (_message != null)
-			Console.Error.WriteLine($"  = note: {_message}");
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(_willExit, _code, _label, _message, file: _file);
+		}
+		else {
+			var type = _willExit ? "Error" : "Warning";
+			Console.Error.WriteLine($"{type}[{_code}]: {_label}");
+			if (_file != null)
+				Console.Error.WriteLine($"  --> {_file}");
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
+		}
+
 		if (_willExit)
 			Environment.Exit(1);
 		return this;
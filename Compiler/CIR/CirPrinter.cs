@@ -6,6 +6,7 @@
 // license terms provided with the Cloth Compiler source distribution.
 
 using System.Text;
+using FrontEnd.Utilities;
 
 namespace Compiler.CIR;
 
@@ -27,10 +28,116 @@ public static class CirPrinter {
 		return sb.ToString();
 	}
 
+	// Signatures only: each type with its header, fields, enum cases, and the functions it owns
+	// (with recursive statement counts), then functions no listed type owns, then static fields.
+	public static string PrintOutline(CirModule module) {
+		var sb = new StringBuilder();
+		var (owned, other) = GroupByOwner(module);
+
+		sb.AppendLine($"Types ({module.Types.Count}):");
+		foreach (var type in module.Types) {
+			sb.Append($"  {TypeDeclKind(type)} {TypeDeclName(type)}");
+			if (type is CirTypeDecl.Class c) {
+				if (c.BaseClass != null) sb.Append($" extends {c.BaseClass}");
+				if (c.Interfaces.Count > 0) sb.Append($" is {string.Join(", ", c.Interfaces)}");
+			}
+
+			sb.AppendLine();
+			foreach (var f in TypeDeclFields(type))
+				sb.AppendLine($"    field {f.Name}: {PrintType(f.Type)}");
+			if (type is CirTypeDecl.Enum e)
+				foreach (var ec in e.Cases)
+					sb.AppendLine($"    case {ec.Name}");
+			foreach (var fn in owned[TypeDeclName(type)])
+				PrintFunctionOutline(sb, fn, MemberName(fn, TypeDeclName(type)), indent: 4);
+		}
+
+		sb.AppendLine($"Other functions ({other.Count}):");
+		foreach (var fn in other)
+			PrintFunctionOutline(sb, fn, fn.MangledName, indent: 2);
+
+		sb.AppendLine($"Static fields ({module.StaticFields.Count}):");
+		foreach (var sf in module.StaticFields)
+			sb.AppendLine($"  {sf.ClassFqn}.{sf.Name}: {PrintType(sf.Type)}{(sf.IsConst ? " [const]" : "")}");
+
+		sb.Append($"Vtables: {module.Vtables.Count}");
+		return sb.ToString();
+	}
+
+	// Same content as PrintOutline, as JSON for tooling.
+	public static string PrintOutlineJson(CirModule module) {
+		var (owned, other) = GroupByOwner(module);
+
+		var outline = new {
+			Types = module.Types.Select(t => new {
+				Kind = TypeDeclKind(t),
+				Name = TypeDeclName(t),
+				BaseClass = (t as CirTypeDecl.Class)?.BaseClass,
+				Interfaces = (t as CirTypeDecl.Class)?.Interfaces,
+				Fields = TypeDeclFields(t).Select(f => new { f.Name, Type = PrintType(f.Type) }),
+				Cases = (t as CirTypeDecl.Enum)?.Cases.Select(ec => ec.Name),
+				Functions = owned[TypeDeclName(t)].Select(fn => FunctionOutline(fn, MemberName(fn, TypeDeclName(t))))
+			}),
+			OtherFunctions = other.Select(fn => FunctionOutline(fn, fn.MangledName)),
+			StaticFields = module.StaticFields.Select(sf => new { Class = sf.ClassFqn, sf.Name, Type = PrintType(sf.Type), sf.IsConst }),
+			Vtables = module.Vtables.Count
+		};
+
+		return ToolingJson.Serialize(outline);
+	}
+
+	// Assigns each function to the type whose FQN prefixes its mangled name (`Type.member...`),
+	// preferring the longest match so nested classes win over their outer class. Functions with
+	// custom extern symbols match no type and are returned separately.
+	private static (Dictionary<string, List<CirFunction>> Owned, List<CirFunction> Other) GroupByOwner(CirModule module) {
+		var owned = module.Types.Select(TypeDeclName).Distinct().ToDictionary(n => n, _ => new List<CirFunction>());
+		var byLength = owned.Keys.OrderByDescending(n => n.Length).ToList();
+		var other = new List<CirFunction>();
+
+		foreach (var fn in module.Functions) {
+			var owner = byLength.FirstOrDefault(n => fn.MangledName.StartsWith(n + "."));
+			if (owner != null) owned[owner].Add(fn);
+			else other.Add(fn);
+		}
+
+		return (owned, other);
+	}
+
+	// `Type.name__i32__string` → `name`; the parameter list carries the overload information.
+	private static string MemberName(CirFunction fn, string ownerFqn) {
+		var member = fn.MangledName[(ownerFqn.Length + 1)..];
+		var overload = member.IndexOf("__", StringComparison.Ordinal);
+		return overload < 0 ? member : member[..overload];
+	}
+
 	// -------------------------------------------------------------------------
 	// Type declarations
 	// -------------------------------------------------------------------------
 
+	private static string TypeDeclKind(CirTypeDecl decl) => decl switch {
+		CirTypeDecl.Class => "class",
+		CirTypeDecl.Struct => "struct",
+		CirTypeDecl.Enum => "enum",
+		CirTypeDecl.Interface => "interface",
+		CirTypeDecl.Trait => "trait",
+		_ => decl.GetType().Name
+	};
+
+	private static string TypeDeclName(CirTypeDecl decl) => decl switch {
+		CirTypeDecl.Class c => c.FullyQualifiedName,
+		CirTypeDecl.Struct s => s.FullyQualifiedName,
+		CirTypeDecl.Enum e => e.FullyQualifiedName,
+		CirTypeDecl.Interface i => i.FullyQualifiedName,
+		CirTypeDecl.Trait t => t.FullyQualifiedName,
+		_ => decl.GetType().Name
+	};
+
+	private static List<CirField> TypeDeclFields(CirTypeDecl decl) => decl switch {
+		CirTypeDecl.Class c => c.Fields,
+		CirTypeDecl.Struct s => s.Fields,
+		_ => []
+	};
+
 	private static void PrintTypeDecl(StringBuilder sb, CirTypeDecl decl, int indent) {
 		var pad = new string(' ', indent);
 		switch (decl) {
@@ -80,14 +187,7 @@ public static class CirPrinter {
 
 	private static void PrintFunction(StringBuilder sb, CirFunction fn, int indent) {
 		var pad = new string(' ', indent);
-		var kind = fn.Kind switch {
-			CirFunctionKind.Constructor => "ctor",
-			CirFunctionKind.Destructor => "dtor",
-			CirFunctionKind.Method => "fn",
-			CirFunctionKind.Fragment => "fragment",
-			CirFunctionKind.StaticMethod => "static fn",
-			_ => "fn"
-		};
+		var kind = FunctionKind(fn.Kind);
 		var paramStr = string.Join(", ", fn.Parameters.Select(p => $"{p.Name}: {PrintType(p.Type)}"));
 		var ret = PrintType(fn.ReturnType);
 		var extern_ = fn.IsExtern ? " [extern]" : "";
@@ -98,10 +198,50 @@ public static class CirPrinter {
 		sb.AppendLine($"{pad}}}");
 	}
 
+	private static void PrintFunctionOutline(StringBuilder sb, CirFunction fn, string name, int indent) {
+		var pad = new string(' ', indent);
+		var paramStr = string.Join(", ", fn.Parameters.Select(p => $"{p.Name}: {PrintType(p.Type)}"));
+		var extern_ = fn.IsExtern ? " [extern]" : "";
+		sb.AppendLine($"{pad}{FunctionKind(fn.Kind)} {name}({paramStr}) -> {PrintType(fn.ReturnType)}{extern_}    // {CountStatements(fn.Body)} statements");
+	}
+
+	private static object FunctionOutline(CirFunction fn, string name) => new {
+		Kind = FunctionKind(fn.Kind),
+		Name = name,
+		fn.MangledName,
+		Parameters = fn.Parameters.Select(p => new { p.Name, Type = PrintType(p.Type) }),
+		ReturnType = PrintType(fn.ReturnType),
+		fn.IsExtern,
+		fn.IsStatic,
+		Statements = CountStatements(fn.Body)
+	};
+
+	private static string FunctionKind(CirFunctionKind kind) => kind switch {
+		CirFunctionKind.Constructor => "ctor",
+		CirFunctionKind.Destructor => "dtor",
+		CirFunctionKind.Method => "fn",
+		CirFunctionKind.Fragment => "fragment",
+		CirFunctionKind.StaticMethod => "static fn",
+		_ => "fn"
+	};
+
 	// -------------------------------------------------------------------------
 	// Statements
 	// -------------------------------------------------------------------------
 
+	// Counts every statement in the body, including those nested in control flow, so a function
+	// whose whole body sits inside one `if` is not reported as a single statement.
+	private static int CountStatements(IEnumerable<CirStmt> body) => body.Sum(stmt => 1 + stmt switch {
+		CirStmt.If i => CountStatements(i.Then) + i.ElseIfs.Sum(e => CountStatements(e.Body)) + CountStatements(i.Else ?? []),
+		CirStmt.While w => CountStatements(w.Body),
+		CirStmt.DoWhile d => CountStatements(d.Body),
+		CirStmt.For f => CountStatements([f.Init]) + CountStatements(f.Body),
+		CirStmt.ForIn f => CountStatements(f.Body),
+		CirStmt.Switch s => s.Cases.Sum(c => CountStatements(c.Body)),
+		CirStmt.Block b => CountStatements(b.Body),
+		_ => 0
+	});
+
 	private static void PrintStmt(StringBuilder sb, CirStmt stmt, int indent) {
 		var pad = new string(' ', indent);
 		switch (stmt) {
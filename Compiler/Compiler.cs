@@ -34,18 +34,62 @@ public class Compiler(string projectRoot) {
 	/// compilation process, representing the intermediate state of the program.
 	/// </returns>
 	public CIR.CirModule Compile() {
+		var (config, units, symbols, analyzer) = RunFrontEnd();
+
+		var cirGenerator = new CirGenerator(symbols);
+		var module = cirGenerator.Generate(units, analyzer.InferredVarTypes);
+
+		var emitter = new LlvmEmitter(module, config, projectRoot);
+		var llPath = emitter.Emit();
+
+		if (config.Build.OutputType == OutputType.Library) {
+			BuildLibrary(llPath, config, projectRoot);
+		}
+		else {
+			var libsToLink = ResolveDependencies(config);
+			InvokeClang(llPath, config, projectRoot, libsToLink);
+		}
+
+		return module;
+	}
+
+	/// <summary>
+	/// Parses and semantically analyzes the project without generating CIR, emitting LLVM IR,
+	/// or invoking any native tools. Diagnostics are reported the same way as during a full
+	/// compile; if this method returns, the project passed every check.
+	/// </summary>
+	public void Check() => RunFrontEnd();
+
+	/// <summary>
+	/// Runs the front end and lowers the project to CIR, stopping before LLVM emission. Used by
+	/// tooling that inspects program structure without producing build artifacts.
+	/// </summary>
+	/// <returns>The <see cref="CIR.CirModule"/> a full compile would hand to the LLVM emitter.</returns>
+	public CIR.CirModule Lower() {
+		var (_, units, symbols, analyzer) = RunFrontEnd();
+		return new CirGenerator(symbols).Generate(units, analyzer.InferredVarTypes);
+	}
+
+	/// <summary>
+	/// Runs the front half of the pipeline shared by <see cref="Compile"/>, <see cref="Check"/>, and <see cref="Lower"/>:
+	/// reads build.toml, parses the project's sources (plus the sources of Cloth dependencies for
+	/// executable builds), builds the symbol registry, and runs semantic analysis.
+	/// </summary>
+	/// <returns>
+	/// The project configuration, the parsed user units, the symbol registry, and the analyzer
+	/// whose inferred types feed CIR generation.
+	/// </returns>
+	private (ClothConfig Config, List<(CompilationUnit Unit, string FilePath)> Units, SymbolRegistry Symbols, SemanticAnalyzer Analyzer) RunFrontEnd() {
 		var tomlPath = Path.Combine(projectRoot, "build.toml");
 		if (!File.Exists(tomlPath)) {
-			Console.Error.WriteLine($"Error: build.toml not found in '{projectRoot}'");
-			Environment.Exit(1);
+			BuildError.BuildFileNotFound.WithMessage($"no build.toml in '{projectRoot}'").Render();
 		}
 
 		var config = ConfigReader.Read(tomlPath);
 		var sourceRoot = Path.Combine(projectRoot, config.Build.Source);
 
 		if (!Directory.Exists(sourceRoot)) {
-			Console.Error.WriteLine($"Error: source directory '{sourceRoot}' does not exist");
-			Environment.Exit(1);
+			BuildError.SourceRootNotFound.WithMessage($"'{sourceRoot}' does not exist").Render();
 		}
 
 		var units = ParseUnits(sourceRoot);
@@ -72,21 +116,7 @@ public class Compiler(string projectRoot) {
 		var analyzer = new SemanticAnalyzer(units, sourceRoot, symbols, externUnits, config.Build.AllowLeaks);
 		analyzer.Analyze(requireMain: config.Build.OutputType == OutputType.Executable);
 
-		var cirGenerator = new CirGenerator(symbols);
-		var module = cirGenerator.Generate(units, analyzer.InferredVarTypes);
-
-		var emitter = new LlvmEmitter(module, config, projectRoot);
-		var llPath = emitter.Emit();
-
-		if (config.Build.OutputType == OutputType.Library) {
-			BuildLibrary(llPath, config, projectRoot);
-		}
-		else {
-			var libsToLink = ResolveDependencies(config);
-			InvokeClang(llPath, config, projectRoot, libsToLink);
-		}
-
-		return module;
+		return (config, units, symbols, analyzer);
 	}
 
 	/// <summary>
@@ -164,16 +194,16 @@ public class Compiler(string projectRoot) {
 			if (libPath == null) {
 				var stdlibRoot = ResolveStdlibRoot(name);
 				if (stdlibRoot == null) {
-					Console.Error.WriteLine($"Error: cannot locate the Cloth standard library for dependency '{name}={version}'.\n" + "  Tried (in order):\n" + "    1. CLOTH_STDLIB_PATH environment variable\n" + $"    2. {Path.Combine(AppContext.BaseDirectory, "Standard-Library")}\n" + "    3. Walking up from the compiler binary's directory\n" + "  Set CLOTH_STDLIB_PATH or place Standard-Library next to the compiler binary.");
-					Environment.Exit(1);
+					BuildError.StdlibNotFound.WithMessage($"required by dependency '{name}={version}'.\n" + "  Tried (in order):\n" + "    1. CLOTH_STDLIB_PATH environment variable\n" + $"    2. {Path.Combine(AppContext.BaseDirectory, "Standard-Library")}\n" + "    3. Walking up from the compiler binary's directory\n" + "  Set CLOTH_STDLIB_PATH or place Standard-Library next to the compiler binary.").Render();
+					continue;
 				}
 
 				Console.WriteLine($"Building dependency '{name}' from {stdlibRoot}...");
 				new Compiler(stdlibRoot).Compile();
 				libPath = FindCachedLib(name, version);
 				if (libPath == null) {
-					Console.Error.WriteLine($"Error: dependency '{name}={version}' build did not produce a .lib in cache");
-					Environment.Exit(1);
+					BuildError.DependencyLibMissing.WithMessage($"dependency '{name}={version}'").Render();
+					continue;
 				}
 			}
 
@@ -373,9 +403,8 @@ public class Compiler(string projectRoot) {
 		using (proc) {
 			proc.WaitForExit();
 			if (proc.ExitCode != 0) {
-				Console.Error.Write(proc.StandardError.ReadToEnd());
-				Console.Error.Write(proc.StandardOutput.ReadToEnd());
-				Environment.Exit(1);
+				var output = proc.StandardError.ReadToEnd() + proc.StandardOutput.ReadToEnd();
+				BuildError.ToolFailed.WithMessage($"'{tool}' exited with code {proc.ExitCode}:\n{output}").Render();
 			}
 		}
 	}
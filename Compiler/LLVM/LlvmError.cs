@@ -5,6 +5,8 @@
 // Use, modification, and distribution of this file are governed by the
 // license terms provided with the Cloth Compiler source distribution.
 
+using FrontEnd.Error;
+
 namespace Compiler.LLVM;
 
 public class LlvmError : Exception {
@@ -31,10 +33,16 @@ public class LlvmError : Exception {
 	public LlvmError WithMessage(string message) => new(_code, _label, _willExit, message);
 
 	public LlvmError Render() {
-		var type = _willExit ? "Error" : "Warning";
-		Console.Error.WriteLine($"{type}[{_code}]: {_label}");
-		if (_message != null)
-			Console.Error.WriteLine($"  = note: {_message}");
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(_willExit, _code, _label, _message);
+		}
+		else {
+			var type = _willExit ? "Error" : "Warning";
+			Console.Error.WriteLine($"{type}[{_code}]: {_label}");
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
+		}
+
 		if (_willExit)
 			Environment.Exit(1);
 		return this;
@@ -5,6 +5,8 @@
 // Use, modification, and distribution of this file are governed by the
 // license terms provided with the Cloth Compiler source distribution.
 
+using FrontEnd.Error;
+
 namespace Compiler.Semantics;
 
 public class SemanticError : Exception {
@@ -79,12 +81,18 @@ public class SemanticError : Exception {
 	public SemanticError WithSeverity(bool willExit) => new(_code, _label, willExit, _message, _file);
 
 	public SemanticError Render() {
-		var type = _willExit ? "Error" : "Warning";
-		Console.Error.WriteLine($"{type}[{_code}]: {_label}");
-		if (_file != null)
-			Console.Error.WriteLine($"  --> {_file}");
-		if (_message != null)
-			Console.Error.WriteLine($"  = note: {_message}");
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(_willExit, _code, _label, _message, file: _file);
+		}
+		else {
+			var type = _willExit ? "Error" : "Warning";
+			Console.Error.WriteLine($"{type}[{_code}]: {_label}");
+			if (_file != null)
+				Console.Error.WriteLine($"  --> {_file}");
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
+		}
+
 		if (_willExit)
 			Environment.Exit(1);
 		return this;
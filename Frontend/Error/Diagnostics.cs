@@ -0,0 +1,36 @@
+// Copyright (c) 2026.The Cloth contributors.
+// 
+// Diagnostics.cs is part of the Cloth Frontend.
+// 
+// Use, modification, and distribution of this file are governed by the
+// license terms provided with the Cloth Compiler source distribution.
+
+using FrontEnd.Token;
+using FrontEnd.Utilities;
+
+namespace FrontEnd.Error;
+
+public enum DiagnosticFormat {
+	Human,
+	Json
+}
+
+// Process-wide switch read by every error type's Render. Set once by the CLI from
+// `--error-format` before any compilation work starts.
+public static class Diagnostics {
+	public static DiagnosticFormat Format { get; set; } = DiagnosticFormat.Human;
+
+	// Writes one diagnostic as a single JSON object per line on stderr, so tools can read the
+	// stream line by line. `file` is used when no span is available.
+	public static void WriteJson(bool willExit, string code, string label, string? note, string? file = null, TokenSpan? span = null) {
+		var diagnostic = new {
+			Severity = willExit ? "error" : "warning",
+			Code = code,
+			Label = label,
+			Note = note,
+			File = span?.File?.Path ?? file,
+			Span = span == null ? null : new { span.StartLine, span.StartColumn, span.EndLine, span.EndColumn }
+		};
+		Console.Error.WriteLine(ToolingJson.Serialize(diagnostic));
+	}
+}
@@ -19,7 +19,10 @@ public interface IError {
 	}
 
 	public void Render() {
-		Console.Error.WriteLine($"error[{ErrorCode()}]: {GetErrorMessage()}");
+		if (Diagnostics.Format == DiagnosticFormat.Json)
+			Diagnostics.WriteJson(WillExit(), ErrorCode(), GetErrorMessage(), null);
+		else
+			Console.Error.WriteLine($"error[{ErrorCode()}]: {GetErrorMessage()}");
 		if (WillExit()) {
 			Environment.Exit(ExitCode());
 		}
@@ -46,14 +46,20 @@ public class LexerError : Exception, IError {
 	public bool WillExit() => _willExit;
 
 	public void Render() {
-		Console.Error.WriteLine($"Error[{_code}]: {_label}");
-		if (_span != null) {
-			var filePath = _span.File?.Path ?? "<unknown>";
-			Console.Error.WriteLine($"  --> {filePath}:{_span.StartLine}:{_span.StartColumn}");
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(true, _code, _label, _message, span: _span);
+		}
+		else {
+			Console.Error.WriteLine($"Error[{_code}]: {_label}");
+			if (_span != null) {
+				var filePath = _span.File?.Path ?? "<unknown>";
+				Console.Error.WriteLine($"  --> {filePath}:{_span.StartLine}:{_span.StartColumn}");
+			}
+
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
 		}
 
-		if (_message != null)
-			Console.Error.WriteLine($"  = note: {_message}");
 		if (_willExit)
 			Environment.Exit(1);
 	}
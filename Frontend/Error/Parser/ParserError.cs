@@ -46,16 +46,22 @@ public class ParserError : Exception, IError {
 	public bool WillExit() => _willExit;
 
 	public ParserError Render() {
-		var type = _willExit ? "Error" : "Warning";
+		if (Diagnostics.Format == DiagnosticFormat.Json) {
+			Diagnostics.WriteJson(_willExit, _code, _label, _message, span: _span);
+		}
+		else {
+			var type = _willExit ? "Error" : "Warning";
+
+			Console.Error.WriteLine($"{type}[{_code}]: {_label}");
+			if (_span != null) {
+				var filePath = _span.File?.Path ?? "<unknown>";
+				Console.Error.WriteLine($"  --> {filePath}:{_span.StartLine}:{_span.StartColumn}");
+			}
 
-		Console.Error.WriteLine($"{type}[{_code}]: {_label}");
-		if (_span != null) {
-			var filePath = _span.File?.Path ?? "<unknown>";
-			Console.Error.WriteLine($"  --> {filePath}:{_span.StartLine}:{_span.StartColumn}");
+			if (_message != null)
+				Console.Error.WriteLine($"  = note: {_message}");
 		}
 
-		if (_message != null)
-			Console.Error.WriteLine($"  = note: {_message}");
 		if (_willExit)
 			Environment.Exit(1);
 
@@ -0,0 +1,22 @@
+// Copyright (c) 2026.The Cloth contributors.
+// 
+// ToolingJson.cs is part of the Cloth Frontend.
+// 
+// Use, modification, and distribution of this file are governed by the
+// license terms provided with the Cloth Compiler source distribution.
+
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace FrontEnd.Utilities;
+
+// Shared conventions for JSON meant to be read by editors and other tools (`--error-format json`,
+// `inspect --json`): camelCase keys, nulls omitted, one document per line.
+public static class ToolingJson {
+	public static readonly JsonSerializerOptions Options = new() {
+		PropertyNamingPolicy = JsonNamingPolicy.CamelCase,
+		DefaultIgnoreCondition = JsonIgnoreCondition.WhenWritingNull
+	};
+
+	public static string Serialize(object value) => JsonSerializer.Serialize(value, Options);
+}